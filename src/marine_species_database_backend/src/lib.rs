@@ -1,14 +1,24 @@
 #[macro_use]
 extern crate serde;
 use candid::{Decode, Encode};
+use fst::automaton::{Automaton, Levenshtein};
+use fst::{IntoStreamer, Set, Streamer};
 use ic_cdk::api::time;
 use ic_stable_structures::memory_manager::{MemoryId, MemoryManager, VirtualMemory};
 use ic_stable_structures::{BoundedStorable, Cell, DefaultMemoryImpl, StableBTreeMap, Storable};
 use validator::Validate;
+use std::collections::{BTreeMap, HashMap};
 use std::{borrow::Cow, cell::RefCell};
 
+// Max Levenshtein distance for the fuzzy-match automaton.
+const MAX_SEARCH_DISTANCE: u8 = 2;
+
+// Bump in lockstep with the last v(N-1)_to_vN function added below.
+const CURRENT_SCHEMA_VERSION: u32 = 3;
+
 type Memory = VirtualMemory<DefaultMemoryImpl>;
 type IdCell = Cell<u64, Memory>;
+type VersionCell = Cell<u32, Memory>;
 
 #[derive(candid::CandidType, Clone, Serialize, Deserialize)]
 struct Taxonomy {
@@ -30,11 +40,123 @@ struct MarineSpecie {
     name: String,
     habitat: String,
     taxonomy_id: u64, // Reference to Taxonomy by Id
-    conservation_status: String, // Can be eg: Extinct, CriticallyEndagered,, Endagered, vulnerable, LeastConcern
+    conservation_status: ConservationStatus,
+    synonyms: Vec<String>, // Added in schema v2, see v1_to_v2
+    created_at: u64,
+    updated_at: Option<u64>,
+}
+
+// IUCN-style conservation status, normalized from free-form input (see FromStr).
+#[derive(candid::CandidType, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+enum ConservationStatus {
+    Extinct,
+    ExtinctInTheWild,
+    CriticallyEndangered,
+    Endangered,
+    Vulnerable,
+    NearThreatened,
+    LeastConcern,
+    DataDeficient,
+    NotEvaluated,
+}
+
+impl Default for ConservationStatus {
+    fn default() -> Self {
+        ConservationStatus::NotEvaluated
+    }
+}
+
+impl std::str::FromStr for ConservationStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "extinct" | "ex" => Ok(ConservationStatus::Extinct),
+            "extinct in the wild" | "ew" => Ok(ConservationStatus::ExtinctInTheWild),
+            "critically endangered" | "cr" => Ok(ConservationStatus::CriticallyEndangered),
+            "endangered" | "en" => Ok(ConservationStatus::Endangered),
+            "vulnerable" | "vu" => Ok(ConservationStatus::Vulnerable),
+            "near threatened" | "nt" => Ok(ConservationStatus::NearThreatened),
+            "least concern" | "lc" => Ok(ConservationStatus::LeastConcern),
+            "data deficient" | "dd" => Ok(ConservationStatus::DataDeficient),
+            "not evaluated" | "ne" => Ok(ConservationStatus::NotEvaluated),
+            other => Err(format!("Unknown conservation status: {}", other)),
+        }
+    }
+}
+
+impl std::fmt::Display for ConservationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let canonical = match self {
+            ConservationStatus::Extinct => "Extinct",
+            ConservationStatus::ExtinctInTheWild => "Extinct in the Wild",
+            ConservationStatus::CriticallyEndangered => "Critically Endangered",
+            ConservationStatus::Endangered => "Endangered",
+            ConservationStatus::Vulnerable => "Vulnerable",
+            ConservationStatus::NearThreatened => "Near Threatened",
+            ConservationStatus::LeastConcern => "Least Concern",
+            ConservationStatus::DataDeficient => "Data Deficient",
+            ConservationStatus::NotEvaluated => "Not Evaluated",
+        };
+        write!(f, "{}", canonical)
+    }
+}
+
+// MarineSpecie as stored under schema v1, before `synonyms` was added.
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct MarineSpecieV1 {
+    id: u64,
+    name: String,
+    habitat: String,
+    taxonomy_id: u64,
+    conservation_status: String,
+    created_at: u64,
+    updated_at: Option<u64>,
+}
+
+impl Storable for MarineSpecieV1 {
+    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+impl BoundedStorable for MarineSpecieV1 {
+    const MAX_SIZE: u32 = 1024;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// MarineSpecie as stored under schema v2, before conservation_status became an enum.
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct MarineSpecieV2 {
+    id: u64,
+    name: String,
+    habitat: String,
+    taxonomy_id: u64,
+    conservation_status: String,
+    synonyms: Vec<String>,
     created_at: u64,
     updated_at: Option<u64>,
 }
 
+impl Storable for MarineSpecieV2 {
+    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+impl BoundedStorable for MarineSpecieV2 {
+    const MAX_SIZE: u32 = 1024;
+    const IS_FIXED_SIZE: bool = false;
+}
+
 // Implement Storable and BoundedStorable traits for Taxonomy
 impl Storable for Taxonomy {
     fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
@@ -67,6 +189,46 @@ impl BoundedStorable for MarineSpecie {
     const IS_FIXED_SIZE: bool = false;
 }
 
+// Ids of species matching one search token, wrapped since Vec<u64> can't implement a foreign trait directly.
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct PostingList(Vec<u64>);
+
+impl Storable for PostingList {
+    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Sized for ~8k matching ids (Candid encodes each u64 in 8 bytes), not a single record.
+impl BoundedStorable for PostingList {
+    const MAX_SIZE: u32 = 65536;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Ids of species under one taxonomy, as stored in TAXONOMY_SPECIES_INDEX.
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct TaxonomySpeciesList(Vec<u64>);
+
+impl Storable for TaxonomySpeciesList {
+    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Sized for ~8k species ids (Candid encodes each u64 in 8 bytes), not a single record.
+impl BoundedStorable for TaxonomySpeciesList {
+    const MAX_SIZE: u32 = 65536;
+    const IS_FIXED_SIZE: bool = false;
+}
+
 thread_local! {
     static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> = RefCell::new(
         MemoryManager::init(DefaultMemoryImpl::default())
@@ -89,6 +251,108 @@ thread_local! {
         RefCell::new(StableBTreeMap::init(
             MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3)))
         ));
+
+    // Inverted index: lowercased token -> matching marine species ids.
+    static SEARCH_INDEX: RefCell<StableBTreeMap<String, PostingList, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(4)))
+        ));
+
+    // Last schema version migrated against stable memory.
+    static SCHEMA_VERSION: RefCell<VersionCell> = RefCell::new(
+        VersionCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(5))), 0)
+            .expect("Cannot create schema version cell")
+    );
+
+    // Reverse index: taxonomy_id -> ids of marine species referencing it.
+    static TAXONOMY_SPECIES_INDEX: RefCell<StableBTreeMap<u64, TaxonomySpeciesList, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(6)))
+        ));
+}
+
+// A fresh install is already at the current schema, nothing to migrate.
+#[ic_cdk::init]
+fn init() {
+    SCHEMA_VERSION.with(|cell| {
+        cell.borrow_mut()
+            .set(CURRENT_SCHEMA_VERSION)
+            .expect("Cannot persist schema version")
+    });
+}
+
+// Runs the ordered vN_to_vN+1 steps from SCHEMA_VERSION to CURRENT_SCHEMA_VERSION.
+#[ic_cdk::post_upgrade]
+fn post_upgrade() {
+    let stored_version = SCHEMA_VERSION.with(|cell| *cell.borrow().get());
+    migrate_marinespecie_records(stored_version);
+    rebuild_taxonomy_species_index();
+    rebuild_search_index();
+
+    SCHEMA_VERSION.with(|cell| {
+        cell.borrow_mut()
+            .set(CURRENT_SCHEMA_VERSION)
+            .expect("Cannot persist schema version")
+    });
+}
+
+// v1 -> v2: adds MarineSpecie::synonyms, defaulting to an empty list.
+fn v1_to_v2(legacy: MarineSpecieV1) -> MarineSpecieV2 {
+    MarineSpecieV2 {
+        id: legacy.id,
+        name: legacy.name,
+        habitat: legacy.habitat,
+        taxonomy_id: legacy.taxonomy_id,
+        conservation_status: legacy.conservation_status,
+        synonyms: Vec::new(),
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+    }
+}
+
+// v2 -> v3: parses conservation_status into an enum, falling back to NotEvaluated.
+fn v2_to_v3(previous: MarineSpecieV2) -> MarineSpecie {
+    let conservation_status = previous
+        .conservation_status
+        .parse()
+        .unwrap_or(ConservationStatus::NotEvaluated);
+    MarineSpecie {
+        id: previous.id,
+        name: previous.name,
+        habitat: previous.habitat,
+        taxonomy_id: previous.taxonomy_id,
+        conservation_status,
+        synonyms: previous.synonyms,
+        created_at: previous.created_at,
+        updated_at: previous.updated_at,
+    }
+}
+
+// Dispatches every MarineSpecie record through the vN_to_vN+1 chain to CURRENT_SCHEMA_VERSION.
+fn migrate_marinespecie_records(stored_version: u32) {
+    if stored_version >= CURRENT_SCHEMA_VERSION {
+        return;
+    }
+
+    if stored_version < 2 {
+        let legacy_map: StableBTreeMap<u64, MarineSpecieV1, Memory> = StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3))),
+        );
+        let legacy_entries: Vec<(u64, MarineSpecieV1)> = legacy_map.iter().collect();
+        for (_, legacy) in legacy_entries {
+            let migrated = v2_to_v3(v1_to_v2(legacy));
+            MARINESPECIE_STR.with(|service| service.borrow_mut().insert(migrated.id, migrated));
+        }
+    } else {
+        let legacy_map: StableBTreeMap<u64, MarineSpecieV2, Memory> = StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3))),
+        );
+        let legacy_entries: Vec<(u64, MarineSpecieV2)> = legacy_map.iter().collect();
+        for (_, previous) in legacy_entries {
+            let migrated = v2_to_v3(previous);
+            MARINESPECIE_STR.with(|service| service.borrow_mut().insert(migrated.id, migrated));
+        }
+    }
 }
 
 #[derive(candid::CandidType, Serialize, Deserialize, Default, Validate)]
@@ -214,6 +478,12 @@ fn update_taxonomy(id: u64, taxonomy_input: TaxonomyInput) -> Result<Taxonomy, E
 
 // helper method to perform insert.
 fn do_insert_taxonomy(taxonomy: &Taxonomy) {
+    insert_taxonomy_record(taxonomy);
+    rebuild_search_index();
+}
+
+// Same as do_insert_taxonomy but without the search index rebuild.
+fn insert_taxonomy_record(taxonomy: &Taxonomy) {
     TAXONOMY_STR.with(|service| {
         service
             .borrow_mut()
@@ -221,10 +491,34 @@ fn do_insert_taxonomy(taxonomy: &Taxonomy) {
     });
 }
 
+// Deletes a Taxonomy, blocked by dependent species unless `cascade` is set.
 #[ic_cdk::update]
-fn delete_taxonomy(id: u64) -> Result<Taxonomy, Error> {
+fn delete_taxonomy(id: u64, cascade: bool) -> Result<Taxonomy, Error> {
+    let dependent_ids = species_ids_for_taxonomy(id);
+    if !dependent_ids.is_empty() {
+        if !cascade {
+            return Err(Error::ValidationFailed {
+                content: format!(
+                    "Cannot delete Taxonomy with id={}: {} marine species still reference it. Pass cascade=true to delete them too.",
+                    id,
+                    dependent_ids.len()
+                ),
+            });
+        }
+        for species_id in &dependent_ids {
+            MARINESPECIE_STR.with(|service| service.borrow_mut().remove(species_id));
+        }
+        TAXONOMY_SPECIES_INDEX.with(|service| {
+            service.borrow_mut().remove(&id);
+        });
+        rebuild_search_index();
+    }
+
     match TAXONOMY_STR.with(|service| service.borrow_mut().remove(&id)) {
-        Some(taxonomy) => Ok(taxonomy),
+        Some(taxonomy) => {
+            rebuild_search_index();
+            Ok(taxonomy)
+        }
         None => Err(Error::NotFound {
             msg: format!(
                 "Couldn't delete a taxonomy with id={}. Taxonomy not found.",
@@ -234,6 +528,64 @@ fn delete_taxonomy(id: u64) -> Result<Taxonomy, Error> {
     }
 }
 
+// Rolls back to the savepoint on the first invalid item.
+#[ic_cdk::update]
+fn add_taxonomy_batch(taxonomy_inputs: Vec<TaxonomyInput>) -> Result<Vec<Taxonomy>, Error> {
+    let savepoint_counter = TAXONOMY_ID_COUNTER.with(|counter| *counter.borrow().get());
+    let mut inserted_ids: Vec<u64> = Vec::new();
+    let mut taxonomies: Vec<Taxonomy> = Vec::new();
+
+    for (index, input) in taxonomy_inputs.into_iter().enumerate() {
+        if let Err(errors) = input.validate() {
+            rollback_taxonomy_batch(savepoint_counter, &inserted_ids);
+            return Err(Error::ValidationFailed {
+                content: format!("Item {} failed validation: {}", index, errors),
+            });
+        }
+
+        let id = TAXONOMY_ID_COUNTER
+            .with(|counter| {
+                let current_value = *counter.borrow().get();
+                counter.borrow_mut().set(current_value + 1)
+            })
+            .expect("Cannot increment id counter");
+        let taxonomy = Taxonomy {
+            id,
+            kingdom: input.kingdom,
+            phylum: input.phylum,
+            class: input.class,
+            order: input.order,
+            family: input.family,
+            genus: input.genus,
+            species: input.species,
+            created_at: time(),
+            updated_at: None,
+        };
+        insert_taxonomy_record(&taxonomy);
+        inserted_ids.push(id);
+        taxonomies.push(taxonomy);
+    }
+
+    rebuild_search_index();
+    Ok(taxonomies)
+}
+
+fn rollback_taxonomy_batch(savepoint_counter: u64, inserted_ids: &[u64]) {
+    TAXONOMY_STR.with(|service| {
+        let mut map = service.borrow_mut();
+        for id in inserted_ids {
+            map.remove(id);
+        }
+    });
+    TAXONOMY_ID_COUNTER.with(|counter| {
+        counter
+            .borrow_mut()
+            .set(savepoint_counter)
+            .expect("Cannot reset id counter")
+    });
+    rebuild_search_index();
+}
+
 // Marine Specie
 
 #[ic_cdk::query]
@@ -272,6 +624,8 @@ fn _get_marinespecie(id: &u64) -> Option<MarineSpecie> {
 // Get marine specie by conservation_status
 #[ic_cdk::query]
 fn get_marinespecie_by_conservation_status(conservation_status: String) -> Result<Vec<MarineSpecie>, Error> {
+    let status: ConservationStatus = conservation_status.parse().map_err(|content| Error::ValidationFailed { content })?;
+
     let marinespecie_map: Vec<(u64, MarineSpecie)> =
         MARINESPECIE_STR.with(|service| service.borrow().iter().collect());
 
@@ -279,7 +633,7 @@ fn get_marinespecie_by_conservation_status(conservation_status: String) -> Resul
     let marinespecie_in_conservation_status: Vec<MarineSpecie> = marinespecie_map
         .into_iter()
         .map(|(_, marinespecie)| marinespecie)
-        .filter(|marinespecie| marinespecie.conservation_status.to_lowercase() == conservation_status.to_lowercase())
+        .filter(|marinespecie| marinespecie.conservation_status == status)
         .collect();
 
     if !marinespecie_in_conservation_status.is_empty() {
@@ -288,12 +642,67 @@ fn get_marinespecie_by_conservation_status(conservation_status: String) -> Resul
         Err(Error::NotFound {
             msg: format!(
                 "No Marine Specie found in classified conservation_status: {}",
-                conservation_status
+                status
             ),
         })
     }
 }
 
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct MarineSpeciePage {
+    items: Vec<MarineSpecie>,
+    total: u64,
+}
+
+// Paginated listing; unlike get_all_marinespecie, never errors when empty.
+#[ic_cdk::query]
+fn list_marinespecie(offset: u64, limit: u64) -> Result<MarineSpeciePage, Error> {
+    let marinespecies: Vec<MarineSpecie> =
+        MARINESPECIE_STR.with(|service| service.borrow().iter().map(|(_, marinespecie)| marinespecie).collect());
+    let total = marinespecies.len() as u64;
+    let items: Vec<MarineSpecie> = marinespecies
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect();
+
+    Ok(MarineSpeciePage { items, total })
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct FacetCounts {
+    by_conservation_status: Vec<(String, u64)>,
+    by_class: Vec<(String, u64)>,
+    by_family: Vec<(String, u64)>,
+}
+
+// Facet distributions by conservation status, class, and family.
+#[ic_cdk::query]
+fn facet_counts() -> Result<FacetCounts, Error> {
+    let marinespecies: Vec<MarineSpecie> =
+        MARINESPECIE_STR.with(|service| service.borrow().iter().map(|(_, marinespecie)| marinespecie).collect());
+
+    let mut by_conservation_status: BTreeMap<String, u64> = BTreeMap::new();
+    let mut by_class: BTreeMap<String, u64> = BTreeMap::new();
+    let mut by_family: BTreeMap<String, u64> = BTreeMap::new();
+
+    for marinespecie in &marinespecies {
+        *by_conservation_status
+            .entry(marinespecie.conservation_status.to_string())
+            .or_insert(0) += 1;
+        if let Some(taxonomy) = _get_taxonomy(&marinespecie.taxonomy_id) {
+            *by_class.entry(taxonomy.class).or_insert(0) += 1;
+            *by_family.entry(taxonomy.family).or_insert(0) += 1;
+        }
+    }
+
+    Ok(FacetCounts {
+        by_conservation_status: by_conservation_status.into_iter().collect(),
+        by_class: by_class.into_iter().collect(),
+        by_family: by_family.into_iter().collect(),
+    })
+}
+
 #[ic_cdk::update]
 fn add_marinespecie(marinespecie_input: MarineSpecieInput) -> Result<MarineSpecie, Error> {
     let check_input = marinespecie_input.validate();
@@ -302,6 +711,17 @@ fn add_marinespecie(marinespecie_input: MarineSpecieInput) -> Result<MarineSpeci
             content: check_input.err().unwrap().to_string(),
         });
     }
+    let conservation_status: ConservationStatus = marinespecie_input
+        .conservation_status
+        .parse()
+        .map_err(|content| Error::ValidationFailed { content })?;
+
+    if _get_taxonomy(&marinespecie_input.taxonomy_id).is_none() {
+        return Err(Error::NotFound {
+            msg: format!("Taxonomy with id={} not found", marinespecie_input.taxonomy_id),
+        });
+    }
+
     let id = MARINESPECIE_ID_COUNTER
         .with(|counter| {
             let current_value = *counter.borrow().get();
@@ -314,7 +734,8 @@ fn add_marinespecie(marinespecie_input: MarineSpecieInput) -> Result<MarineSpeci
         name: marinespecie_input.name,
         habitat: marinespecie_input.habitat,
         taxonomy_id: marinespecie_input.taxonomy_id,
-        conservation_status: marinespecie_input.conservation_status,
+        conservation_status,
+        synonyms: Vec::new(),
         created_at: time(),
         updated_at: None,
     };
@@ -330,12 +751,23 @@ fn update_marinespecie(id: u64, marinespecie_input: MarineSpecieInput) -> Result
             content: check_input.err().unwrap().to_string(),
         });
     }
+    let conservation_status: ConservationStatus = marinespecie_input
+        .conservation_status
+        .parse()
+        .map_err(|content| Error::ValidationFailed { content })?;
+
+    if _get_taxonomy(&marinespecie_input.taxonomy_id).is_none() {
+        return Err(Error::NotFound {
+            msg: format!("Taxonomy with id={} not found", marinespecie_input.taxonomy_id),
+        });
+    }
+
     match MARINESPECIE_STR.with(|service| service.borrow().get(&id)) {
         Some(mut marinespecie) => {
             marinespecie.name = marinespecie_input.name;
             marinespecie.habitat = marinespecie_input.habitat;
             marinespecie.taxonomy_id = marinespecie_input.taxonomy_id;
-            marinespecie.conservation_status = marinespecie_input.conservation_status;
+            marinespecie.conservation_status = conservation_status;
             marinespecie.updated_at = Some(time());
             do_insert_marinespecie(&marinespecie);
             Ok(marinespecie)
@@ -348,13 +780,89 @@ fn update_marinespecie(id: u64, marinespecie_input: MarineSpecieInput) -> Result
 
 // helper method to perform insert.
 fn do_insert_marinespecie(marinespecie: &MarineSpecie) {
+    insert_marinespecie_record(marinespecie);
+    rebuild_search_index();
+}
+
+// Same as do_insert_marinespecie but without the search index rebuild.
+fn insert_marinespecie_record(marinespecie: &MarineSpecie) {
+    let previous_taxonomy_id =
+        MARINESPECIE_STR.with(|service| service.borrow().get(&marinespecie.id).map(|m| m.taxonomy_id));
+    if let Some(previous_taxonomy_id) = previous_taxonomy_id {
+        if previous_taxonomy_id != marinespecie.taxonomy_id {
+            remove_species_from_taxonomy_index(previous_taxonomy_id, marinespecie.id);
+        }
+    }
+    add_species_to_taxonomy_index(marinespecie.taxonomy_id, marinespecie.id);
+
     MARINESPECIE_STR.with(|service| service.borrow_mut().insert(marinespecie.id, marinespecie.clone()));
 }
 
+// Keeps TAXONOMY_SPECIES_INDEX in lockstep with MARINESPECIE_STR writes.
+fn add_species_to_taxonomy_index(taxonomy_id: u64, species_id: u64) {
+    TAXONOMY_SPECIES_INDEX.with(|service| {
+        let mut index = service.borrow_mut();
+        let mut ids = index.get(&taxonomy_id).map(|list| list.0).unwrap_or_default();
+        if !ids.contains(&species_id) {
+            ids.push(species_id);
+        }
+        index.insert(taxonomy_id, TaxonomySpeciesList(ids));
+    });
+}
+
+fn remove_species_from_taxonomy_index(taxonomy_id: u64, species_id: u64) {
+    TAXONOMY_SPECIES_INDEX.with(|service| {
+        let mut index = service.borrow_mut();
+        if let Some(TaxonomySpeciesList(mut ids)) = index.get(&taxonomy_id) {
+            ids.retain(|&id| id != species_id);
+            if ids.is_empty() {
+                index.remove(&taxonomy_id);
+            } else {
+                index.insert(taxonomy_id, TaxonomySpeciesList(ids));
+            }
+        }
+    });
+}
+
+fn species_ids_for_taxonomy(taxonomy_id: u64) -> Vec<u64> {
+    TAXONOMY_SPECIES_INDEX.with(|service| {
+        service
+            .borrow()
+            .get(&taxonomy_id)
+            .map(|list| list.0)
+            .unwrap_or_default()
+    })
+}
+
+// Rebuilds TAXONOMY_SPECIES_INDEX from MARINESPECIE_STR.
+fn rebuild_taxonomy_species_index() {
+    let mut dependents: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+    MARINESPECIE_STR.with(|service| {
+        for (id, marinespecie) in service.borrow().iter() {
+            dependents.entry(marinespecie.taxonomy_id).or_default().push(id);
+        }
+    });
+
+    TAXONOMY_SPECIES_INDEX.with(|service| {
+        let mut index = service.borrow_mut();
+        let stale_ids: Vec<u64> = index.iter().map(|(taxonomy_id, _)| taxonomy_id).collect();
+        for taxonomy_id in stale_ids {
+            index.remove(&taxonomy_id);
+        }
+        for (taxonomy_id, ids) in dependents {
+            index.insert(taxonomy_id, TaxonomySpeciesList(ids));
+        }
+    });
+}
+
 #[ic_cdk::update]
 fn delete_marinespecie(id: u64) -> Result<MarineSpecie, Error> {
     match MARINESPECIE_STR.with(|service| service.borrow_mut().remove(&id)) {
-        Some(marinespecie) => Ok(marinespecie),
+        Some(marinespecie) => {
+            remove_species_from_taxonomy_index(marinespecie.taxonomy_id, marinespecie.id);
+            rebuild_search_index();
+            Ok(marinespecie)
+        }
         None => Err(Error::NotFound {
             msg: format!(
                 "Couldn't delete a marinespecie with id={}.",
@@ -364,6 +872,215 @@ fn delete_marinespecie(id: u64) -> Result<MarineSpecie, Error> {
     }
 }
 
+// Same validate-then-commit, savepoint/rollback shape as add_taxonomy_batch.
+#[ic_cdk::update]
+fn add_marinespecie_batch(marinespecie_inputs: Vec<MarineSpecieInput>) -> Result<Vec<MarineSpecie>, Error> {
+    let savepoint_counter = MARINESPECIE_ID_COUNTER.with(|counter| *counter.borrow().get());
+    let mut inserted_ids: Vec<u64> = Vec::new();
+    let mut marinespecies: Vec<MarineSpecie> = Vec::new();
+
+    for (index, input) in marinespecie_inputs.into_iter().enumerate() {
+        if let Err(errors) = input.validate() {
+            rollback_marinespecie_batch(savepoint_counter, &inserted_ids);
+            return Err(Error::ValidationFailed {
+                content: format!("Item {} failed validation: {}", index, errors),
+            });
+        }
+
+        let conservation_status: ConservationStatus = match input.conservation_status.parse() {
+            Ok(status) => status,
+            Err(content) => {
+                rollback_marinespecie_batch(savepoint_counter, &inserted_ids);
+                return Err(Error::ValidationFailed {
+                    content: format!("Item {} failed validation: {}", index, content),
+                });
+            }
+        };
+
+        if _get_taxonomy(&input.taxonomy_id).is_none() {
+            rollback_marinespecie_batch(savepoint_counter, &inserted_ids);
+            return Err(Error::NotFound {
+                msg: format!("Item {}: Taxonomy with id={} not found", index, input.taxonomy_id),
+            });
+        }
+
+        let id = MARINESPECIE_ID_COUNTER
+            .with(|counter| {
+                let current_value = *counter.borrow().get();
+                counter.borrow_mut().set(current_value + 1)
+            })
+            .expect("Cannot increment id counter");
+        let marinespecie = MarineSpecie {
+            id,
+            name: input.name,
+            habitat: input.habitat,
+            taxonomy_id: input.taxonomy_id,
+            conservation_status,
+            synonyms: Vec::new(),
+            created_at: time(),
+            updated_at: None,
+        };
+        insert_marinespecie_record(&marinespecie);
+        inserted_ids.push(id);
+        marinespecies.push(marinespecie);
+    }
+
+    rebuild_search_index();
+    Ok(marinespecies)
+}
+
+fn rollback_marinespecie_batch(savepoint_counter: u64, inserted_ids: &[u64]) {
+    for id in inserted_ids {
+        if let Some(marinespecie) = MARINESPECIE_STR.with(|service| service.borrow_mut().remove(id)) {
+            remove_species_from_taxonomy_index(marinespecie.taxonomy_id, marinespecie.id);
+        }
+    }
+    MARINESPECIE_ID_COUNTER.with(|counter| {
+        counter
+            .borrow_mut()
+            .set(savepoint_counter)
+            .expect("Cannot reset id counter")
+    });
+    rebuild_search_index();
+}
+
+// Typo-tolerant full-text search over SEARCH_INDEX's tokens via fst::Set.
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_string())
+        .collect()
+}
+
+fn marinespecie_tokens(marinespecie: &MarineSpecie) -> Vec<String> {
+    let mut tokens = tokenize(&marinespecie.name);
+    tokens.extend(tokenize(&marinespecie.habitat));
+    if let Some(taxonomy) = _get_taxonomy(&marinespecie.taxonomy_id) {
+        tokens.extend(tokenize(&taxonomy.genus));
+        tokens.extend(tokenize(&taxonomy.species));
+        tokens.extend(tokenize(&taxonomy.family));
+    }
+    tokens
+}
+
+// Rebuilds SEARCH_INDEX from scratch.
+fn rebuild_search_index() {
+    let mut postings: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    MARINESPECIE_STR.with(|service| {
+        for (id, marinespecie) in service.borrow().iter() {
+            for token in marinespecie_tokens(&marinespecie) {
+                postings.entry(token).or_default().push(id);
+            }
+        }
+    });
+
+    SEARCH_INDEX.with(|service| {
+        let mut index = service.borrow_mut();
+        let stale_tokens: Vec<String> = index.iter().map(|(token, _)| token).collect();
+        for token in stale_tokens {
+            index.remove(&token);
+        }
+        for (token, ids) in postings {
+            index.insert(token, PostingList(ids));
+        }
+    });
+}
+
+fn build_token_fst() -> Option<Set<Vec<u8>>> {
+    let tokens: Vec<String> = SEARCH_INDEX.with(|service| service.borrow().iter().map(|(token, _)| token).collect());
+    Set::from_iter(tokens).ok()
+}
+
+// Plain Levenshtein distance, used to break ties between candidates.
+fn edit_distance(a: &str, b: &str) -> u32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<u32> = (0..=b.len() as u32).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i as u32;
+        for j in 1..=b.len() {
+            let tmp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = tmp;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[ic_cdk::query]
+fn search_marinespecie(query: String, max_distance: u8) -> Result<Vec<MarineSpecie>, Error> {
+    let max_distance = max_distance.min(MAX_SEARCH_DISTANCE) as u32;
+    let query_tokens = tokenize(&query);
+    if query_tokens.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+
+    let token_fst = match build_token_fst() {
+        Some(set) => set,
+        None => {
+            return Err(Error::NotFound {
+                msg: "Search index could not be built.".to_string(),
+            })
+        }
+    };
+
+    // species id -> (number of query tokens matched, best edit distance)
+    let mut scores: HashMap<u64, (u32, u32)> = HashMap::new();
+
+    for query_token in &query_tokens {
+        let automaton = match Levenshtein::new(query_token, max_distance) {
+            Ok(automaton) => automaton,
+            Err(_) => continue,
+        };
+
+        // species id -> best edit distance for this query token
+        let mut token_matches: HashMap<u64, u32> = HashMap::new();
+        let mut stream = token_fst.search(&automaton).into_stream();
+        while let Some(matched_token) = stream.next() {
+            let matched_token = String::from_utf8_lossy(matched_token).to_string();
+            let distance = edit_distance(query_token, &matched_token);
+            let postings = SEARCH_INDEX.with(|service| service.borrow().get(&matched_token));
+            if let Some(PostingList(ids)) = postings {
+                for id in ids {
+                    let best = token_matches.entry(id).or_insert(distance);
+                    *best = (*best).min(distance);
+                }
+            }
+        }
+
+        for (id, distance) in token_matches {
+            let entry = scores.entry(id).or_insert((0, distance));
+            entry.0 += 1;
+            entry.1 = entry.1.min(distance);
+        }
+    }
+
+    let mut ranked: Vec<(u64, (u32, u32))> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1 .0.cmp(&a.1 .0).then(a.1 .1.cmp(&b.1 .1)));
+
+    let results: Vec<MarineSpecie> = ranked
+        .into_iter()
+        .filter_map(|(id, _)| _get_marinespecie(&id))
+        .collect();
+
+    if results.is_empty() {
+        Err(Error::NotFound {
+            msg: format!("No marine species matched query: {}", query),
+        })
+    } else {
+        Ok(results)
+    }
+}
+
 #[derive(candid::CandidType, Deserialize, Serialize)]
 enum Error {
     NotFound { msg: String },
@@ -371,5 +1088,83 @@ enum Error {
     InvalidInput,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v1_records_to_current_schema() {
+        let mut v1_map: StableBTreeMap<u64, MarineSpecieV1, Memory> =
+            StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3))));
+        v1_map.insert(
+            1,
+            MarineSpecieV1 {
+                id: 1,
+                name: "European eel".to_string(),
+                habitat: "Freshwater".to_string(),
+                taxonomy_id: 1,
+                conservation_status: "cr".to_string(),
+                created_at: time(),
+                updated_at: None,
+            },
+        );
+
+        migrate_marinespecie_records(1);
+
+        let migrated = MARINESPECIE_STR
+            .with(|service| service.borrow().get(&1))
+            .expect("migrated record should exist");
+        assert_eq!(migrated.synonyms, Vec::<String>::new());
+        assert_eq!(
+            migrated.conservation_status,
+            ConservationStatus::CriticallyEndangered
+        );
+    }
+
+    fn add_taxonomy(taxonomy_id: u64) {
+        let taxonomy = Taxonomy {
+            id: taxonomy_id,
+            kingdom: "Animalia".to_string(),
+            phylum: "Chordata".to_string(),
+            class: "Actinopterygii".to_string(),
+            order: "Anguilliformes".to_string(),
+            family: "Anguillidae".to_string(),
+            genus: "Anguilla".to_string(),
+            species: "anguilla".to_string(),
+            created_at: time(),
+            updated_at: None,
+        };
+        do_insert_taxonomy(&taxonomy);
+    }
+
+    #[test]
+    fn add_marinespecie_batch_rolls_back_on_validation_failure() {
+        add_taxonomy(1);
+        let savepoint_counter = MARINESPECIE_ID_COUNTER.with(|counter| *counter.borrow().get());
+
+        let result = add_marinespecie_batch(vec![
+            MarineSpecieInput {
+                name: "Clownfish".to_string(),
+                habitat: "Reef".to_string(),
+                taxonomy_id: 1,
+                conservation_status: "lc".to_string(),
+            },
+            MarineSpecieInput {
+                name: "Invalid".to_string(),
+                habitat: "Reef".to_string(),
+                taxonomy_id: 1,
+                conservation_status: "not a real status".to_string(),
+            },
+        ]);
+
+        assert!(matches!(result, Err(Error::ValidationFailed { .. })));
+        assert_eq!(
+            MARINESPECIE_ID_COUNTER.with(|counter| *counter.borrow().get()),
+            savepoint_counter
+        );
+        assert!(MARINESPECIE_STR.with(|service| service.borrow().is_empty()));
+    }
+}
+
 // need this to generate candid
 ic_cdk::export_candid!();